@@ -1,30 +1,310 @@
+use std::cell::RefCell;
 use std::collections::HashMap;
 use std::ffi::CStr;
+use std::rc::Rc;
+
+use libc::{c_ulong, c_void};
+use mysqlclient_sys as ffi;
 
 use super::{Binds, Statement, StatementMetadata};
 use crate::mysql::{Mysql, MysqlType, MysqlValue};
 use crate::result::QueryResult;
 use crate::row::*;
 
+impl Statement {
+    /// Advances to the next result set of a `CALL`/multi-statement
+    /// response via `mysql_stmt_next_result`.
+    ///
+    /// Returns `Ok(true)` if another result set is now current and ready
+    /// to have its metadata/binds rebuilt, or `Ok(false)` if there are no
+    /// more result sets (`mysql_stmt_next_result` returned -1).
+    fn advance_to_next_result_set(&mut self) -> QueryResult<bool> {
+        match unsafe { ffi::mysql_stmt_next_result(self.stmt_ptr()) } {
+            0 => Ok(true),
+            -1 => Ok(false),
+            _ => Err(self.last_error()),
+        }
+    }
+
+    /// Rebinds `binds` as the output buffers for the *current* result set
+    /// via `mysql_stmt_bind_result`, without re-running
+    /// `mysql_stmt_execute`. Used after [`Statement::advance_to_next_result_set`]
+    /// returns `Ok(true)`, since the statement has already executed and
+    /// only the new result set's binds need to be attached.
+    fn bind_result(&mut self, binds: &mut Binds) -> QueryResult<()> {
+        let result = unsafe { ffi::mysql_stmt_bind_result(self.stmt_ptr(), binds.mysql_binds()) };
+        if result == 0 {
+            Ok(())
+        } else {
+            Err(self.last_error())
+        }
+    }
+
+    /// Selects whether this statement uses a server-side, read-only cursor
+    /// (`mysql_stmt_attr_set` with `STMT_ATTR_CURSOR_TYPE`) instead of
+    /// buffering the whole result set client-side, and for
+    /// [`CursorType::Streaming`], how many rows the client prefetches per
+    /// round-trip (`STMT_ATTR_PREFETCH_ROWS`). Must be called before
+    /// [`Statement::execute_statement`].
+    fn set_cursor_type(&mut self, cursor_type: CursorType) -> QueryResult<()> {
+        let raw_cursor_type: c_ulong = match cursor_type {
+            CursorType::Buffered => ffi::mysql_cursor_type::CURSOR_TYPE_NO_CURSOR as c_ulong,
+            CursorType::Streaming { .. } => {
+                ffi::mysql_cursor_type::CURSOR_TYPE_READ_ONLY as c_ulong
+            }
+        };
+        self.set_attr(
+            ffi::mysql_stmt_attr_type::STMT_ATTR_CURSOR_TYPE,
+            &raw_cursor_type as *const c_ulong as *const c_void,
+        )?;
+
+        if let CursorType::Streaming { prefetch_rows } = cursor_type {
+            let raw_prefetch_rows = prefetch_rows as c_ulong;
+            self.set_attr(
+                ffi::mysql_stmt_attr_type::STMT_ATTR_PREFETCH_ROWS,
+                &raw_prefetch_rows as *const c_ulong as *const c_void,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn set_attr(
+        &mut self,
+        attr: ffi::mysql_stmt_attr_type,
+        value: *const c_void,
+    ) -> QueryResult<()> {
+        let result = unsafe { ffi::mysql_stmt_attr_set(self.stmt_ptr(), attr, value) };
+        if result == 0 {
+            Ok(())
+        } else {
+            Err(self.last_error())
+        }
+    }
+
+    /// Resets the statement (`mysql_stmt_reset`), closing any open
+    /// server-side cursor and discarding unread rows so the connection is
+    /// free to run other statements again.
+    fn reset(&mut self) -> QueryResult<()> {
+        let result = unsafe { ffi::mysql_stmt_reset(self.stmt_ptr()) };
+        if result == 0 {
+            Ok(())
+        } else {
+            Err(self.last_error())
+        }
+    }
+}
+
+/// Identifies a [`BindBufferPool`] bucket: a server column layout paired
+/// with the decode types the caller wants for those columns.
+///
+/// Two statements can report the same server-side column layout (e.g. both
+/// return a single `VARCHAR` column) while asking for different Rust/SQL
+/// decode types on it (e.g. one reads it as `Text`, the other as `Binary`).
+/// A `Binds` built for one isn't safe to hand to the other, so both halves
+/// have to be part of the key, not just the layout.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct PoolKey {
+    column_layout: Vec<MysqlFieldType>,
+    decode_types: Vec<Option<String>>,
+}
+
+impl StatementMetadata {
+    /// Builds the [`BindBufferPool`] key for output binds decoded with the
+    /// given per-column types. `types[i] == None` means "decode column `i`
+    /// using its native metadata type" (as [`NamedStatementIterator`] always
+    /// does), so callers that don't have caller-chosen decode types can pass
+    /// an all-`None` vec the length of the column count.
+    fn pool_key(&self, types: &[Option<MysqlType>]) -> PoolKey {
+        PoolKey {
+            column_layout: self.column_layout(),
+            decode_types: types
+                .iter()
+                .map(|ty| ty.as_ref().map(|ty| format!("{:?}", ty)))
+                .collect(),
+        }
+    }
+}
+
+/// A connection-scoped pool of reusable [`Binds`] output buffers, keyed by
+/// [`PoolKey`] (the column layout and decode types of the result set they
+/// were built for).
+///
+/// Building `Binds` for a prepared statement allocates one buffer per output
+/// column; for a statement that's executed many times in a loop (e.g. inside
+/// a batch import), that allocation dominates. Handing the same connection's
+/// `BindBufferPool` to repeated calls to [`StatementIterator::with_pool`] or
+/// [`NamedStatementIterator::with_pool`] lets those calls reuse buffers from
+/// the last execution with a matching key instead of allocating fresh ones.
+///
+/// Buffers are reset (via `Binds::reset_for_reuse`) as they're handed back
+/// out of the pool in [`BindBufferPool::take`], so stale data from a
+/// previous execution can't leak into the next one regardless of what
+/// `execute_statement` does on its own.
+#[derive(Clone, Default)]
+pub struct BindBufferPool {
+    inner: Rc<RefCell<HashMap<PoolKey, Vec<Binds>>>>,
+}
+
+impl BindBufferPool {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn take(&self, key: &PoolKey) -> Option<Binds> {
+        let mut binds = self.inner.borrow_mut().get_mut(key).and_then(Vec::pop)?;
+        binds.reset_for_reuse();
+        Some(binds)
+    }
+
+    fn give_back(&self, key: PoolKey, binds: Binds) {
+        self.inner.borrow_mut().entry(key).or_default().push(binds);
+    }
+}
+
+/// Controls whether a statement buffers its entire result set on the client
+/// (the default `mysql_stmt_store_result` behavior) or keeps a server-side,
+/// read-only cursor open and pulls rows in prefetched batches.
+///
+/// While a cursor is open the connection cannot be used to run other
+/// statements until the cursor is exhausted (or explicitly closed), so
+/// [`StatementIterator`] and [`NamedStatementIterator`] free the cursor as
+/// soon as they observe the end of the result set.
+#[derive(Debug, Clone, Copy)]
+pub enum CursorType {
+    /// Buffer the whole result set on the client, as today.
+    Buffered,
+    /// Keep a server-side cursor open and prefetch rows in batches of the
+    /// given size.
+    Streaming { prefetch_rows: u32 },
+}
+
+impl CursorType {
+    /// The default prefetch batch size used by [`CursorType::streaming`].
+    const DEFAULT_PREFETCH_ROWS: u32 = 100;
+
+    /// Construct a streaming cursor with the default prefetch batch size.
+    pub fn streaming() -> Self {
+        CursorType::Streaming {
+            prefetch_rows: Self::DEFAULT_PREFETCH_ROWS,
+        }
+    }
+}
+
+impl Default for CursorType {
+    fn default() -> Self {
+        CursorType::Buffered
+    }
+}
+
 pub struct StatementIterator<'a> {
     stmt: &'a mut Statement,
     output_binds: Binds,
+    types: Vec<Option<MysqlType>>,
+    cursor_type: CursorType,
+    cursor_exhausted: bool,
+    binds_given_back: bool,
+    pool: Option<BindBufferPool>,
 }
 
 #[allow(clippy::should_implement_trait)] // don't neet `Iterator` here
 impl<'a> StatementIterator<'a> {
     #[allow(clippy::new_ret_no_self)]
     pub fn new(stmt: &'a mut Statement, types: Vec<Option<MysqlType>>) -> QueryResult<Self> {
-        let mut output_binds = if types.iter().any(Option::is_none) {
-            let metadata = stmt.metadata()?;
-            Binds::from_output_types(types, Some(metadata.fields()))
+        Self::with_cursor_type(stmt, types, CursorType::default())
+    }
+
+    /// Like [`StatementIterator::new`], but allows opting into a server-side
+    /// streaming cursor instead of buffering the whole result set client-side.
+    #[allow(clippy::new_ret_no_self)]
+    pub fn with_cursor_type(
+        stmt: &'a mut Statement,
+        types: Vec<Option<MysqlType>>,
+        cursor_type: CursorType,
+    ) -> QueryResult<Self> {
+        Self::with_pool(stmt, types, cursor_type, None)
+    }
+
+    /// Like [`StatementIterator::with_cursor_type`], but draws the output
+    /// binds from `pool` (if given) instead of always allocating fresh
+    /// buffers, and returns them to the pool once the result set is done
+    /// being read.
+    #[allow(clippy::new_ret_no_self)]
+    pub fn with_pool(
+        stmt: &'a mut Statement,
+        types: Vec<Option<MysqlType>>,
+        cursor_type: CursorType,
+        pool: Option<BindBufferPool>,
+    ) -> QueryResult<Self> {
+        let mut output_binds = Self::build_binds(stmt, &types, pool.as_ref())?;
+
+        stmt.set_cursor_type(cursor_type)?;
+        stmt.execute_statement(&mut output_binds)?;
+
+        Ok(StatementIterator {
+            stmt,
+            output_binds,
+            types,
+            cursor_type,
+            cursor_exhausted: false,
+            binds_given_back: false,
+            pool,
+        })
+    }
+
+    fn build_binds(
+        stmt: &mut Statement,
+        types: &[Option<MysqlType>],
+        pool: Option<&BindBufferPool>,
+    ) -> QueryResult<Binds> {
+        let metadata = if types.iter().any(Option::is_none) || pool.is_some() {
+            Some(stmt.metadata()?)
         } else {
-            Binds::from_output_types(types, None)
+            None
         };
 
-        stmt.execute_statement(&mut output_binds)?;
+        if let (Some(pool), Some(metadata)) = (pool, &metadata) {
+            if let Some(binds) = pool.take(&metadata.pool_key(types)) {
+                return Ok(binds);
+            }
+        }
+
+        Ok(Binds::from_output_types(
+            types.to_vec(),
+            metadata.as_ref().map(StatementMetadata::fields),
+        ))
+    }
+
+    /// Advances to the next result set produced by a `CALL` to a stored
+    /// procedure or by a multi-statement batch, if there is one.
+    ///
+    /// Returns `Ok(false)` once there are no more result sets. Each result
+    /// set can have its own column metadata, so the output binds are rebuilt
+    /// for the new set (taken from the pool if one was given and it holds
+    /// matching binds, otherwise freshly allocated); the statement has
+    /// already executed, so only its result binds are rebound, not
+    /// re-executed.
+    ///
+    /// Unlike row exhaustion, advancing to a further result set never resets
+    /// the cursor first: for [`CursorType::Streaming`], `mysql_stmt_reset`
+    /// would discard the remaining result sets before `mysql_stmt_next_result`
+    /// got a chance to advance to them.
+    pub fn next_result_set(&mut self) -> QueryResult<bool> {
+        self.give_back_binds_to_pool();
 
-        Ok(StatementIterator { stmt, output_binds })
+        if !self.stmt.advance_to_next_result_set()? {
+            // No further result sets: this is the real end, so clean up the
+            // cursor exactly as exhausting the last row would.
+            self.close_cursor_if_needed();
+            return Ok(false);
+        }
+
+        self.output_binds = Self::build_binds(self.stmt, &self.types, self.pool.as_ref())?;
+        self.stmt.bind_result(&mut self.output_binds)?;
+        self.cursor_exhausted = false;
+        self.binds_given_back = false;
+
+        Ok(true)
     }
 
     pub fn map<F, T>(mut self, mut f: F) -> QueryResult<Vec<T>>
@@ -45,10 +325,249 @@ impl<'a> StatementIterator<'a> {
                 binds: &mut self.output_binds,
                 stmt: &self.stmt,
             })),
-            Ok(None) => None,
+            Ok(None) => {
+                self.close_cursor_if_needed();
+                None
+            }
             Err(e) => Some(Err(e)),
         }
     }
+
+    /// Frees the server-side cursor once the result set has been fully
+    /// consumed, so the connection can be used for other statements again.
+    /// No-op for [`CursorType::Buffered`] or if already closed.
+    fn close_cursor_if_needed(&mut self) {
+        if self.cursor_exhausted {
+            return;
+        }
+        self.cursor_exhausted = true;
+        if let CursorType::Streaming { .. } = self.cursor_type {
+            let _ = self.stmt.reset();
+        }
+    }
+
+    /// Returns `output_binds` to the pool it was drawn from, if any, so a
+    /// later statement with the same column layout and decode types can
+    /// reuse its buffers instead of allocating fresh ones.
+    fn give_back_binds_to_pool(&mut self) {
+        if self.binds_given_back {
+            return;
+        }
+        self.binds_given_back = true;
+        if let Some(pool) = &self.pool {
+            let metadata = match self.stmt.metadata() {
+                Ok(metadata) => metadata,
+                Err(_) => return,
+            };
+            let key = metadata.pool_key(&self.types);
+            let placeholder = Binds::from_output_types(Vec::new(), None);
+            let binds = std::mem::replace(&mut self.output_binds, placeholder);
+            pool.give_back(key, binds);
+        }
+    }
+}
+
+impl<'a> Drop for StatementIterator<'a> {
+    fn drop(&mut self) {
+        self.give_back_binds_to_pool();
+        self.close_cursor_if_needed();
+    }
+}
+
+/// The MySQL wire protocol type of a result column, as reported by
+/// `MYSQL_FIELD::type_`.
+///
+/// This mirrors the server's `enum_field_types`, which is a superset of (and
+/// doesn't map 1:1 onto) [`MysqlType`], since it also distinguishes things
+/// like `VARCHAR` from `VAR_STRING` that diesel treats the same way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MysqlFieldType {
+    Decimal,
+    Tiny,
+    Short,
+    Long,
+    Float,
+    Double,
+    Null,
+    Timestamp,
+    LongLong,
+    Int24,
+    Date,
+    Time,
+    DateTime,
+    Year,
+    NewDate,
+    VarChar,
+    Bit,
+    Json,
+    NewDecimal,
+    Enum,
+    Set,
+    TinyBlob,
+    MediumBlob,
+    LongBlob,
+    Blob,
+    VarString,
+    String,
+    Geometry,
+    /// A field type diesel doesn't have a variant for yet. Carries the raw
+    /// `enum_field_types` value so callers can still branch on it.
+    Unknown(u32),
+}
+
+/// Bit flags describing a result column, as reported by `MYSQL_FIELD::flags`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColumnFlags(u32);
+
+impl ColumnFlags {
+    const NOT_NULL: u32 = 1;
+    const PRIMARY_KEY: u32 = 1 << 1;
+    const UNIQUE_KEY: u32 = 1 << 2;
+    const MULTIPLE_KEY: u32 = 1 << 3;
+    const UNSIGNED: u32 = 1 << 5;
+    const AUTO_INCREMENT: u32 = 1 << 9;
+
+    fn from_bits_truncate(bits: u32) -> Self {
+        ColumnFlags(bits)
+    }
+
+    fn contains(self, flag: u32) -> bool {
+        self.0 & flag == flag
+    }
+}
+
+/// A `describe`-style summary of a single result column, for callers that
+/// need to inspect the shape of a result set without decoding any rows
+/// (dynamic query runners, schema introspection, RDBC-style tooling).
+///
+/// Owns its strings rather than borrowing from the statement's result
+/// metadata, so it can outlive the [`StatementMetadata`] (or temporary row)
+/// it was read from.
+#[derive(Debug, Clone)]
+pub struct ColumnMetadata {
+    pub name: String,
+    pub table: Option<String>,
+    pub org_table: Option<String>,
+    pub column_type: MysqlFieldType,
+    pub length: u64,
+    /// The maximum length of this column's value *in the rows fetched so
+    /// far*, as reported by `MYSQL_FIELD::max_length`. The client library
+    /// only computes this once the result set has been buffered
+    /// (`mysql_stmt_store_result`), which doesn't happen on the
+    /// [`CursorType::Streaming`] path, so this is `0` there even though the
+    /// column itself may hold longer values. Meaningful only for buffered
+    /// result sets; use [`ColumnMetadata::length`] for the column's declared
+    /// maximum width instead.
+    pub max_length: u64,
+    pub flags: ColumnFlags,
+}
+
+impl ColumnMetadata {
+    pub fn is_not_null(&self) -> bool {
+        self.flags.contains(ColumnFlags::NOT_NULL)
+    }
+
+    pub fn is_unsigned(&self) -> bool {
+        self.flags.contains(ColumnFlags::UNSIGNED)
+    }
+
+    pub fn is_primary_key(&self) -> bool {
+        self.flags.contains(ColumnFlags::PRIMARY_KEY)
+    }
+
+    pub fn is_unique_key(&self) -> bool {
+        self.flags.contains(ColumnFlags::UNIQUE_KEY)
+    }
+
+    pub fn is_multiple_key(&self) -> bool {
+        self.flags.contains(ColumnFlags::MULTIPLE_KEY)
+    }
+
+    pub fn is_auto_increment(&self) -> bool {
+        self.flags.contains(ColumnFlags::AUTO_INCREMENT)
+    }
+}
+
+impl StatementMetadata {
+    /// Returns a `describe`-style summary of the column at `idx`, or `None`
+    /// if `idx` is out of range.
+    pub fn column_metadata(&self, idx: usize) -> Option<ColumnMetadata> {
+        let field = *self.fields().get(idx)?;
+        Some(ColumnMetadata {
+            name: unsafe { c_str_to_str(field.name) }.to_owned(),
+            table: unsafe { c_str_to_str_opt(field.table) }.map(str::to_owned),
+            org_table: unsafe { c_str_to_str_opt(field.org_table) }.map(str::to_owned),
+            column_type: mysql_field_type_from_raw(field.type_ as u32),
+            length: field.length as u64,
+            max_length: field.max_length as u64,
+            flags: ColumnFlags::from_bits_truncate(field.flags as u32),
+        })
+    }
+
+    /// The column layout used as a [`BindBufferPool`] key: the MySQL field
+    /// type of each output column, in order.
+    fn column_layout(&self) -> Vec<MysqlFieldType> {
+        self.fields()
+            .iter()
+            .map(|field| mysql_field_type_from_raw(field.type_ as u32))
+            .collect()
+    }
+}
+
+fn mysql_field_type_from_raw(ty: u32) -> MysqlFieldType {
+    match ty {
+        0 => MysqlFieldType::Decimal,
+        1 => MysqlFieldType::Tiny,
+        2 => MysqlFieldType::Short,
+        3 => MysqlFieldType::Long,
+        4 => MysqlFieldType::Float,
+        5 => MysqlFieldType::Double,
+        6 => MysqlFieldType::Null,
+        7 => MysqlFieldType::Timestamp,
+        8 => MysqlFieldType::LongLong,
+        9 => MysqlFieldType::Int24,
+        10 => MysqlFieldType::Date,
+        11 => MysqlFieldType::Time,
+        12 => MysqlFieldType::DateTime,
+        13 => MysqlFieldType::Year,
+        14 => MysqlFieldType::NewDate,
+        15 => MysqlFieldType::VarChar,
+        16 => MysqlFieldType::Bit,
+        245 => MysqlFieldType::Json,
+        246 => MysqlFieldType::NewDecimal,
+        247 => MysqlFieldType::Enum,
+        248 => MysqlFieldType::Set,
+        249 => MysqlFieldType::TinyBlob,
+        250 => MysqlFieldType::MediumBlob,
+        251 => MysqlFieldType::LongBlob,
+        252 => MysqlFieldType::Blob,
+        253 => MysqlFieldType::VarString,
+        254 => MysqlFieldType::String,
+        255 => MysqlFieldType::Geometry,
+        other => MysqlFieldType::Unknown(other),
+    }
+}
+
+unsafe fn c_str_to_str<'a>(ptr: *const std::os::raw::c_char) -> &'a str {
+    CStr::from_ptr(ptr).to_str().expect(
+        "Diesel assumes that your mysql database uses the \
+         utf8mb4 encoding. That's not the case if you hit \
+         this error message.",
+    )
+}
+
+unsafe fn c_str_to_str_opt<'a>(ptr: *const std::os::raw::c_char) -> Option<&'a str> {
+    if ptr.is_null() {
+        None
+    } else {
+        let s = c_str_to_str(ptr);
+        if s.is_empty() {
+            None
+        } else {
+            Some(s)
+        }
+    }
 }
 
 pub struct MysqlRow<'a> {
@@ -92,28 +611,121 @@ impl<'a> Row<Mysql> for MysqlRow<'a> {
     }
 }
 
+impl<'a> MysqlRow<'a> {
+    /// Returns a `describe`-style summary (type, source table, length,
+    /// nullability/flags) of the column at `idx`, or `None` if there's no
+    /// such column.
+    pub fn column_metadata(&self, idx: usize) -> Option<ColumnMetadata> {
+        let metadata = self
+            .stmt
+            .metadata()
+            .expect("Failed to get result metadata from the mysql backend");
+        metadata.column_metadata(idx)
+    }
+}
+
 pub struct NamedStatementIterator<'a> {
     stmt: &'a mut Statement,
     output_binds: Binds,
     metadata: StatementMetadata,
+    cursor_type: CursorType,
+    cursor_exhausted: bool,
+    binds_given_back: bool,
+    pool: Option<BindBufferPool>,
 }
 
 #[allow(clippy::should_implement_trait)] // don't need `Iterator` here
 impl<'a> NamedStatementIterator<'a> {
     #[allow(clippy::new_ret_no_self)]
     pub fn new(stmt: &'a mut Statement) -> QueryResult<Self> {
+        Self::with_cursor_type(stmt, CursorType::default())
+    }
+
+    /// Like [`NamedStatementIterator::new`], but allows opting into a
+    /// server-side streaming cursor instead of buffering the whole result
+    /// set client-side.
+    #[allow(clippy::new_ret_no_self)]
+    pub fn with_cursor_type(stmt: &'a mut Statement, cursor_type: CursorType) -> QueryResult<Self> {
+        Self::with_pool(stmt, cursor_type, None)
+    }
+
+    /// Like [`NamedStatementIterator::with_cursor_type`], but draws the
+    /// output binds from `pool` (if given) instead of always allocating
+    /// fresh buffers, and returns them to the pool once the result set is
+    /// done being read.
+    #[allow(clippy::new_ret_no_self)]
+    pub fn with_pool(
+        stmt: &'a mut Statement,
+        cursor_type: CursorType,
+        pool: Option<BindBufferPool>,
+    ) -> QueryResult<Self> {
         let metadata = stmt.metadata()?;
-        let mut output_binds = Binds::from_result_metadata(metadata.fields());
+        let mut output_binds = Self::build_binds(&metadata, pool.as_ref());
 
+        stmt.set_cursor_type(cursor_type)?;
         stmt.execute_statement(&mut output_binds)?;
 
         Ok(NamedStatementIterator {
             stmt,
             output_binds,
             metadata,
+            cursor_type,
+            cursor_exhausted: false,
+            binds_given_back: false,
+            pool,
         })
     }
 
+    fn build_binds(metadata: &StatementMetadata, pool: Option<&BindBufferPool>) -> Binds {
+        if let Some(pool) = pool {
+            if let Some(binds) = pool.take(&Self::native_pool_key(metadata)) {
+                return binds;
+            }
+        }
+        Binds::from_result_metadata(metadata.fields())
+    }
+
+    /// [`NamedStatementIterator`] always decodes every column using its
+    /// native metadata type (there's no caller-chosen `types` like
+    /// [`StatementIterator`] has), so its pool key always asks for native
+    /// decoding on every column.
+    fn native_pool_key(metadata: &StatementMetadata) -> PoolKey {
+        metadata.pool_key(&vec![None; metadata.fields().len()])
+    }
+
+    /// Advances to the next result set produced by a `CALL` to a stored
+    /// procedure or by a multi-statement batch, if there is one.
+    ///
+    /// Returns `Ok(false)` once there are no more result sets. Each result
+    /// set can have its own column metadata, so the metadata and column
+    /// name lookup table are re-read and the output binds rebuilt for the
+    /// new set (taken from the pool if one was given and it holds matching
+    /// binds, otherwise freshly allocated); the statement has already
+    /// executed, so only its result binds are rebound, not re-executed.
+    ///
+    /// Unlike row exhaustion, advancing to a further result set never resets
+    /// the cursor first: for [`CursorType::Streaming`], `mysql_stmt_reset`
+    /// would discard the remaining result sets before `mysql_stmt_next_result`
+    /// got a chance to advance to them.
+    pub fn next_result_set(&mut self) -> QueryResult<bool> {
+        self.give_back_binds_to_pool();
+
+        if !self.stmt.advance_to_next_result_set()? {
+            // No further result sets: this is the real end, so clean up the
+            // cursor exactly as exhausting the last row would.
+            self.close_cursor_if_needed();
+            return Ok(false);
+        }
+
+        self.metadata = self.stmt.metadata()?;
+        self.output_binds = Self::build_binds(&self.metadata, self.pool.as_ref());
+        self.stmt.bind_result(&mut self.output_binds)?;
+        self.cursor_exhausted = false;
+        self.binds_given_back = false;
+
+        Ok(true)
+    }
+
     pub fn map<F, T>(mut self, mut f: F) -> QueryResult<Vec<T>>
     where
         F: FnMut(NamedMysqlRow) -> QueryResult<T>,
@@ -130,16 +742,57 @@ impl<'a> NamedStatementIterator<'a> {
             Ok(Some(())) => Some(Ok(NamedMysqlRow {
                 binds: &self.output_binds,
                 column_indices: self.metadata.column_indices(),
+                metadata: &self.metadata,
             })),
-            Ok(None) => None,
+            Ok(None) => {
+                self.close_cursor_if_needed();
+                None
+            }
             Err(e) => Some(Err(e)),
         }
     }
+
+    /// Frees the server-side cursor once the result set has been fully
+    /// consumed, so the connection can be used for other statements again.
+    /// No-op for [`CursorType::Buffered`] or if already closed.
+    fn close_cursor_if_needed(&mut self) {
+        if self.cursor_exhausted {
+            return;
+        }
+        self.cursor_exhausted = true;
+        if let CursorType::Streaming { .. } = self.cursor_type {
+            let _ = self.stmt.reset();
+        }
+    }
+
+    /// Returns `output_binds` to the pool it was drawn from, if any, so a
+    /// later statement with the same column layout can reuse its buffers
+    /// instead of allocating fresh ones.
+    fn give_back_binds_to_pool(&mut self) {
+        if self.binds_given_back {
+            return;
+        }
+        self.binds_given_back = true;
+        if let Some(pool) = &self.pool {
+            let key = Self::native_pool_key(&self.metadata);
+            let placeholder = Binds::from_result_metadata(&[]);
+            let binds = std::mem::replace(&mut self.output_binds, placeholder);
+            pool.give_back(key, binds);
+        }
+    }
+}
+
+impl<'a> Drop for NamedStatementIterator<'a> {
+    fn drop(&mut self) {
+        self.give_back_binds_to_pool();
+        self.close_cursor_if_needed();
+    }
 }
 
 pub struct NamedMysqlRow<'a> {
     binds: &'a Binds,
     column_indices: &'a HashMap<&'a str, usize>,
+    metadata: &'a StatementMetadata,
 }
 
 impl<'a> NamedRow<Mysql> for NamedMysqlRow<'a> {
@@ -151,3 +804,94 @@ impl<'a> NamedRow<Mysql> for NamedMysqlRow<'a> {
         self.binds.field_data(idx)
     }
 }
+
+impl<'a> NamedMysqlRow<'a> {
+    /// Returns a `describe`-style summary (type, source table, length,
+    /// nullability/flags) of the column at `idx`, or `None` if `idx` is out
+    /// of range.
+    pub fn column_metadata(&self, idx: usize) -> Option<ColumnMetadata> {
+        self.metadata.column_metadata(idx)
+    }
+
+    fn column_count(&self) -> usize {
+        self.binds.len()
+    }
+}
+
+/// Runs an arbitrary, already-prepared statement (e.g. one built from a SQL
+/// string at runtime) and yields rows whose shape is inferred entirely from
+/// the result metadata, rather than from a compile-time `QueryFragment`.
+///
+/// This reuses the same output-binds construction [`NamedStatementIterator`]
+/// already relies on (`Binds::from_result_metadata`); the difference is that
+/// the rows it yields carry per-column [`ColumnMetadata`] alongside the raw
+/// value, so callers can choose how to decode a column from its
+/// [`MysqlFieldType`] (`ColumnMetadata::column_type`) instead of a type
+/// chosen at compile time. The column type is reported only as that
+/// wire-level [`MysqlFieldType`]; no conversion to a [`MysqlType`] is
+/// provided. Useful for ad-hoc
+/// query tools, external-dictionary lookups, and ETL where the column set
+/// isn't known ahead of time.
+pub struct DynamicStatementIterator<'a> {
+    inner: NamedStatementIterator<'a>,
+}
+
+impl<'a> DynamicStatementIterator<'a> {
+    pub fn new(stmt: &'a mut Statement) -> QueryResult<Self> {
+        Ok(DynamicStatementIterator {
+            inner: NamedStatementIterator::new(stmt)?,
+        })
+    }
+
+    pub fn map<F, T>(mut self, mut f: F) -> QueryResult<Vec<T>>
+    where
+        F: FnMut(DynamicRow) -> QueryResult<T>,
+    {
+        let mut results = Vec::new();
+        while let Some(row) = self.next() {
+            results.push(f(row?)?);
+        }
+        Ok(results)
+    }
+
+    fn next(&mut self) -> Option<QueryResult<DynamicRow>> {
+        self.inner
+            .next()
+            .map(|row| row.map(|row| DynamicRow { row }))
+    }
+}
+
+/// A single column's dynamically-typed value, paired with the column
+/// metadata needed to interpret it when the result shape isn't known at
+/// compile time.
+pub struct DynamicValue<'a> {
+    pub value: Option<MysqlValue<'a>>,
+    pub column: ColumnMetadata,
+}
+
+/// A row yielded by [`DynamicStatementIterator`], supporting both
+/// positional and by-name column access.
+pub struct DynamicRow<'a> {
+    row: NamedMysqlRow<'a>,
+}
+
+impl<'a> DynamicRow<'a> {
+    /// The number of columns in this row.
+    pub fn column_count(&self) -> usize {
+        self.row.column_count()
+    }
+
+    /// The value and column metadata at the given position, or `None` if
+    /// `idx` is out of range.
+    pub fn get(&self, idx: usize) -> Option<DynamicValue<'_>> {
+        Some(DynamicValue {
+            value: self.row.get_raw_value(idx),
+            column: self.row.column_metadata(idx)?,
+        })
+    }
+
+    /// Like [`DynamicRow::get`], but looks the column up by name.
+    pub fn get_by_name(&self, name: &str) -> Option<DynamicValue<'_>> {
+        self.row.index_of(name).and_then(|idx| self.get(idx))
+    }
+}
@@ -0,0 +1,7 @@
+mod iterator;
+
+pub use self::iterator::{
+    BindBufferPool, ColumnFlags, ColumnMetadata, CursorType, DynamicRow, DynamicStatementIterator,
+    DynamicValue, MysqlFieldType, MysqlRow, NamedMysqlRow, NamedStatementIterator,
+    StatementIterator,
+};